@@ -24,9 +24,10 @@ fn main() {
         }),
     };
 
-    let (cronk_next_hundred, cronk_elapsed) = time!(ScheduleIterator(expression.into_schedule())
-        .take(100)
-        .collect::<Vec<_>>());
+    let (cronk_next_hundred, cronk_elapsed) =
+        time!(ScheduleIterator(expression.into_schedule(Local))
+            .take(100)
+            .collect::<Vec<_>>());
 
     let other_schedule = "0 0 17 13 * Fri".parse::<CronSchedule>().unwrap();
     let (other_next_hundred, other_elapsed) =
@@ -44,12 +45,12 @@ fn main() {
     }
 }
 
-struct ScheduleIterator(Schedule);
+struct ScheduleIterator(Schedule<Local>);
 
 impl Iterator for ScheduleIterator {
     type Item = DateTime<Local>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        Some(self.0.next())
+        self.0.next()
     }
 }
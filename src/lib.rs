@@ -1,7 +1,16 @@
-use chrono::{Date, DateTime, Datelike, Local, LocalResult, TimeZone, Timelike};
+use chrono::{Date, DateTime, Datelike, LocalResult, TimeZone, Timelike, Utc};
 use std::cmp;
 use std::ops;
 
+mod parse;
+
+/// Upper bound on increment attempts before `Schedule::next` gives up, so that
+/// an expression which can never match a real date (e.g. Feb 30) returns `None`
+/// instead of looping forever.
+const MAX_ITER_LOOP: u32 = 500_000;
+
+pub use parse::ParseError;
+
 trait Ticker: Sized + ops::Add + ops::AddAssign + cmp::PartialOrd {
     fn increment(&self) -> Self;
 }
@@ -22,6 +31,7 @@ pub enum Field<T> {
     Single(T),
     Multiple(Vec<T>),
     Range(T, T),
+    Step(T, T, T),
 }
 
 impl<T: Copy> Field<T> {
@@ -30,6 +40,7 @@ impl<T: Copy> Field<T> {
             Field::Single(seed) => seed,
             Field::Multiple(ref set) => *set.first().unwrap(),
             Field::Range(seed, _) => seed,
+            Field::Step(seed, _, _) => seed,
         }
     }
 
@@ -38,6 +49,7 @@ impl<T: Copy> Field<T> {
             Field::Single(x) => Increment::Single(x),
             Field::Multiple(set) => Increment::Multiple(SetTicker::new(set)),
             Field::Range(min, max) => Increment::Range(RangeTicker::new(min, max)),
+            Field::Step(min, max, step) => Increment::Step(StepTicker::new(min, max, step)),
         }
     }
 }
@@ -56,6 +68,9 @@ impl Weekday {
             Field::Single(x) => x == day_of_week,
             Field::Multiple(ref set) => set.contains(&day_of_week),
             Field::Range(min, max) => min <= day_of_week && max >= day_of_week,
+            Field::Step(min, max, step) => {
+                min <= day_of_week && max >= day_of_week && (day_of_week - min).is_multiple_of(step)
+            }
         };
 
         match self.nth {
@@ -77,55 +92,65 @@ pub struct Expression {
 }
 
 impl Expression {
-    pub fn into_schedule(self) -> Schedule {
-        let local = Local::now();
+    /// Builds a schedule seeded from the current instant in `tz`.
+    pub fn into_schedule<Tz: TimeZone>(self, tz: Tz) -> Schedule<Tz> {
+        let now = Utc::now().with_timezone(&tz);
+
+        // Wildcard positions are seeded from the anchor instant, so `with_start`
+        // must know which positions to re-seed when the anchor changes.
+        let wildcard = Wildcards {
+            minute: self.minute.is_none(),
+            hour: self.hour.is_none(),
+            dom: self.dom.is_none(),
+            month: self.month.is_none(),
+        };
 
         let minute = self
             .minute
             .as_ref()
             .map(|x| x.seed())
-            .unwrap_or_else(|| local.minute() as u8);
+            .unwrap_or_else(|| now.minute() as u8);
 
         let hour = self
             .hour
             .as_ref()
             .map(|x| x.seed())
-            .unwrap_or_else(|| local.hour() as u8);
+            .unwrap_or_else(|| now.hour() as u8);
 
         let day = self
             .dom
             .as_ref()
             .map(|x| x.seed())
-            .unwrap_or_else(|| local.day() as u8);
+            .unwrap_or_else(|| now.day() as u8);
 
         let month = self
             .month
             .as_ref()
             .map(|x| x.seed())
-            .unwrap_or_else(|| local.month() as u8);
+            .unwrap_or_else(|| now.month() as u8);
 
         let current = CandidateDateTime {
             minute,
             hour,
             day,
             month,
-            year: local.year(),
+            year: now.year(),
         };
 
         let increment_minute = self.minute.map(Field::into_increment).unwrap_or_else(|| {
-            Increment::Range(RangeTicker::with_current(0, 59, local.minute() as u8))
+            Increment::Range(RangeTicker::with_current(0, 59, now.minute() as u8))
         });
 
         let increment_hour = self.hour.map(Field::into_increment).unwrap_or_else(|| {
-            Increment::Range(RangeTicker::with_current(0, 23, local.hour() as u8))
+            Increment::Range(RangeTicker::with_current(0, 23, now.hour() as u8))
         });
 
         let increment_dom = self.dom.map(Field::into_increment).unwrap_or_else(|| {
-            Increment::Range(RangeTicker::with_current(1, 31, local.day() as u8))
+            Increment::Range(RangeTicker::with_current(1, 31, now.day() as u8))
         });
 
         let increment_month = self.month.map(Field::into_increment).unwrap_or_else(|| {
-            Increment::Range(RangeTicker::with_current(1, 12, local.month() as u8))
+            Increment::Range(RangeTicker::with_current(1, 12, now.month() as u8))
         });
 
         Schedule {
@@ -135,38 +160,200 @@ impl Expression {
             increment_dom,
             increment_month,
             dow: self.dow,
+            not_before: now,
+            count: None,
+            until: None,
+            yielded: 0,
+            last: None,
+            previous: None,
+            wildcard,
+            tz,
         }
     }
 }
 
-pub struct Schedule {
+/// Records which positions were left wildcard (`*`) so their seeds can be
+/// recomputed when the start anchor is moved.
+struct Wildcards {
+    minute: bool,
+    hour: bool,
+    dom: bool,
+    month: bool,
+}
+
+pub struct Schedule<Tz: TimeZone> {
     current: CandidateDateTime,
     increment_minute: Increment<u8>,
     increment_hour: Increment<u8>,
     increment_dom: Increment<u8>,
     increment_month: Increment<u8>,
     dow: Option<Weekday>,
+    not_before: DateTime<Tz>,
+    count: Option<u32>,
+    until: Option<DateTime<Tz>>,
+    yielded: u32,
+    last: Option<DateTime<Tz>>,
+    previous: Option<Checkpoint<Tz>>,
+    wildcard: Wildcards,
+    tz: Tz,
 }
 
-impl Schedule {
-    pub fn next(&mut self) -> DateTime<Local> {
+/// A snapshot of the mutable iteration state, saved before each occurrence so
+/// `rollback` can re-yield the one just produced.
+struct Checkpoint<Tz: TimeZone> {
+    current: CandidateDateTime,
+    increment_minute: Increment<u8>,
+    increment_hour: Increment<u8>,
+    increment_dom: Increment<u8>,
+    increment_month: Increment<u8>,
+    yielded: u32,
+    last: Option<DateTime<Tz>>,
+}
+
+impl<Tz: TimeZone> Schedule<Tz> {
+    /// Enumerates occurrences at or after `start` (inclusive), rather than from
+    /// the schedule's current instant.
+    ///
+    /// Wildcard positions are re-seeded from `start` so enumeration actually
+    /// begins near it, rather than crawling forward from "now" one increment at
+    /// a time (which would drop occurrences before "now" and could exhaust the
+    /// [`MAX_ITER_LOOP`] guard for a far-future start).
+    pub fn with_start(mut self, start: DateTime<Tz>) -> Schedule<Tz> {
+        if self.wildcard.minute {
+            self.current.minute = start.minute() as u8;
+            self.increment_minute =
+                Increment::Range(RangeTicker::with_current(0, 59, start.minute() as u8));
+        }
+
+        if self.wildcard.hour {
+            self.current.hour = start.hour() as u8;
+            self.increment_hour =
+                Increment::Range(RangeTicker::with_current(0, 23, start.hour() as u8));
+        }
+
+        if self.wildcard.dom {
+            self.current.day = start.day() as u8;
+            self.increment_dom =
+                Increment::Range(RangeTicker::with_current(1, 31, start.day() as u8));
+        }
+
+        if self.wildcard.month {
+            self.current.month = start.month() as u8;
+            self.increment_month =
+                Increment::Range(RangeTicker::with_current(1, 12, start.month() as u8));
+        }
+
+        self.current.year = start.year();
+        self.not_before = start;
+        self
+    }
+
+    /// Stops the schedule after `count` occurrences have been yielded.
+    pub fn with_count(mut self, count: u32) -> Schedule<Tz> {
+        self.count = Some(count);
+        self
+    }
+
+    /// Stops the schedule once an occurrence would fall after `until`.
+    pub fn with_until(mut self, until: DateTime<Tz>) -> Schedule<Tz> {
+        self.until = Some(until);
+        self
+    }
+
+    pub fn next(&mut self) -> Option<DateTime<Tz>> {
+        self.checkpoint();
+        self.advance()
+    }
+
+    /// Advances past `n` occurrences without materializing them, leaving the
+    /// schedule positioned so the next `next()` yields the following one.
+    pub fn skip(&mut self, n: usize) -> &mut Schedule<Tz> {
+        for _ in 0..n {
+            self.checkpoint();
+            if self.advance().is_none() {
+                break;
+            }
+        }
+        self
+    }
+
+    /// Steps back to re-yield the occurrence produced by the most recent
+    /// `next()`/`skip()`. Only a single step of history is retained.
+    pub fn rollback(&mut self) -> &mut Schedule<Tz> {
+        if let Some(previous) = self.previous.take() {
+            self.current = previous.current;
+            self.increment_minute = previous.increment_minute;
+            self.increment_hour = previous.increment_hour;
+            self.increment_dom = previous.increment_dom;
+            self.increment_month = previous.increment_month;
+            self.yielded = previous.yielded;
+            self.last = previous.last;
+        }
+        self
+    }
+
+    fn checkpoint(&mut self) {
+        self.previous = Some(Checkpoint {
+            current: self.current.clone(),
+            increment_minute: self.increment_minute.clone(),
+            increment_hour: self.increment_hour.clone(),
+            increment_dom: self.increment_dom.clone(),
+            increment_month: self.increment_month.clone(),
+            yielded: self.yielded,
+            last: self.last.clone(),
+        });
+    }
+
+    fn advance(&mut self) -> Option<DateTime<Tz>> {
+        if let Some(count) = self.count {
+            if self.yielded >= count {
+                return None;
+            }
+        }
+
+        let mut attempts = 0;
         loop {
+            attempts += 1;
+            if attempts > MAX_ITER_LOOP {
+                return None;
+            }
+
             self.increment_date();
-            let candidate = Local.ymd_opt(
+            let candidate = self.tz.ymd_opt(
                 self.current.year,
                 self.current.month as u32,
                 self.current.day as u32,
             );
 
-            // FIXME: the not-earlier-than time filter is probably ineffective, because it's
-            // only testing the date, not the hours/minutes/seconds.
-            if let LocalResult::Single(candidate) = candidate {
-                if candidate >= Local::today() && self.is_valid_weekday(&candidate) {
-                    return candidate.and_hms(
+            if let LocalResult::Single(date) = candidate {
+                if self.is_valid_weekday(&date) {
+                    let candidate = date.and_hms(
                         self.current.hour as u32,
                         self.current.minute as u32,
                         0,
                     );
+
+                    if candidate >= self.not_before {
+                        if let Some(until) = self.until.as_ref() {
+                            if candidate > *until {
+                                return None;
+                            }
+                        }
+
+                        // The tickers re-emit their seed when a higher-order
+                        // field first rolls over, which would otherwise repeat
+                        // the opening block of occurrences. Enforce strictly
+                        // increasing output so enumeration is exactly-once.
+                        if let Some(last) = self.last.as_ref() {
+                            if candidate <= *last {
+                                continue;
+                            }
+                        }
+
+                        self.last = Some(candidate.clone());
+                        self.yielded += 1;
+                        return Some(candidate);
+                    }
                 }
             }
         }
@@ -225,6 +412,7 @@ impl Schedule {
 }
 
 /// Represents a datetime-like value which may or may not be a valid datetime.
+#[derive(Clone)]
 struct CandidateDateTime {
     minute: u8,
     hour: u8,
@@ -233,13 +421,15 @@ struct CandidateDateTime {
     year: i32,
 }
 
+#[derive(Clone)]
 enum Increment<T> {
     Single(T),
     Multiple(SetTicker<T>),
     Range(RangeTicker<T>),
+    Step(StepTicker<T>),
 }
 
-impl<T: Copy + Ticker> Iterator for Increment<T> {
+impl<T: Copy + Ticker + ops::Add<Output = T>> Iterator for Increment<T> {
     type Item = (T, bool);
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -247,10 +437,12 @@ impl<T: Copy + Ticker> Iterator for Increment<T> {
             Increment::Single(x) => Some((x, true)),
             Increment::Multiple(ref mut x) => x.next(),
             Increment::Range(ref mut x) => x.next(),
+            Increment::Step(ref mut x) => x.next(),
         }
     }
 }
 
+#[derive(Clone)]
 struct SetTicker<T> {
     idx: usize,
     set: Vec<T>,
@@ -283,6 +475,7 @@ impl<T: Copy> Iterator for SetTicker<T> {
     }
 }
 
+#[derive(Clone)]
 struct RangeTicker<T> {
     min: T,
     max: T,
@@ -320,3 +513,101 @@ impl<T: Copy + Ticker> Iterator for RangeTicker<T> {
         }
     }
 }
+
+#[derive(Clone)]
+struct StepTicker<T> {
+    min: T,
+    max: T,
+    step: T,
+    current: T,
+}
+
+impl<T: Copy> StepTicker<T> {
+    fn new(min: T, max: T, step: T) -> StepTicker<T> {
+        StepTicker {
+            min,
+            max,
+            step,
+            current: min,
+        }
+    }
+}
+
+impl<T: Copy + Ticker + ops::Add<Output = T>> Iterator for StepTicker<T> {
+    type Item = (T, bool);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.current {
+            current if current >= self.min && current <= self.max => {
+                self.current += self.step;
+                Some((current, false))
+            }
+
+            _ => {
+                self.current = self.min + self.step;
+                Some((self.min, true))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    fn occurrences<Tz: TimeZone>(mut schedule: Schedule<Tz>, n: usize) -> Vec<DateTime<Tz>> {
+        let mut fired = Vec::new();
+        while fired.len() < n {
+            match schedule.next() {
+                Some(datetime) => fired.push(datetime),
+                None => break,
+            }
+        }
+        fired
+    }
+
+    #[test]
+    fn step_minutes_are_monotonic_and_unique() {
+        let start = Utc.ymd(2026, 1, 1).and_hms(9, 0, 0);
+        let schedule = "*/15 * * * *"
+            .parse::<Expression>()
+            .unwrap()
+            .into_schedule(Utc)
+            .with_start(start);
+
+        let fired = occurrences(schedule, 6);
+        let expected = [
+            Utc.ymd(2026, 1, 1).and_hms(9, 0, 0),
+            Utc.ymd(2026, 1, 1).and_hms(9, 15, 0),
+            Utc.ymd(2026, 1, 1).and_hms(9, 30, 0),
+            Utc.ymd(2026, 1, 1).and_hms(9, 45, 0),
+            Utc.ymd(2026, 1, 1).and_hms(10, 0, 0),
+            Utc.ymd(2026, 1, 1).and_hms(10, 15, 0),
+        ];
+        assert_eq!(fired.as_slice(), expected.as_slice());
+
+        for pair in fired.windows(2) {
+            assert!(pair[0] < pair[1], "occurrences must be strictly increasing");
+        }
+    }
+
+    #[test]
+    fn nth_weekday_fires_once_per_month() {
+        let start = Utc.ymd(2026, 1, 1).and_hms(0, 0, 0);
+        let schedule = "0 17 * * 5#2"
+            .parse::<Expression>()
+            .unwrap()
+            .into_schedule(Utc)
+            .with_start(start);
+
+        let fired = occurrences(schedule, 2);
+        assert_eq!(
+            fired,
+            vec![
+                Utc.ymd(2026, 1, 9).and_hms(17, 0, 0),
+                Utc.ymd(2026, 2, 13).and_hms(17, 0, 0),
+            ]
+        );
+    }
+}
@@ -0,0 +1,178 @@
+use std::error;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{Expression, Field, Nth, Weekday};
+
+/// The error returned when a cron line cannot be turned into an [`Expression`].
+#[derive(Debug)]
+pub enum ParseError {
+    /// A cron line must have five or six whitespace-separated fields.
+    FieldCount(usize),
+    /// A numeric field contained something that was not a `u8`.
+    Number(String),
+    /// A day-of-week field contained an unrecognized name.
+    Weekday(String),
+    /// A value fell outside the position's permitted range (`value`, `min`, `max`).
+    OutOfRange(u8, u8, u8),
+    /// A step field used a step of zero, which can never advance.
+    ZeroStep,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ParseError::FieldCount(n) => {
+                write!(f, "expected five or six fields, found {}", n)
+            }
+            ParseError::Number(ref s) => write!(f, "invalid number: {}", s),
+            ParseError::Weekday(ref s) => write!(f, "invalid weekday: {}", s),
+            ParseError::OutOfRange(value, min, max) => {
+                write!(f, "{} is outside the range {}-{}", value, min, max)
+            }
+            ParseError::ZeroStep => write!(f, "step must be greater than zero"),
+        }
+    }
+}
+
+impl error::Error for ParseError {}
+
+impl FromStr for Expression {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Expression, ParseError> {
+        let fields: Vec<&str> = s.split_whitespace().collect();
+
+        // A six-field line carries a leading seconds column (systemd/Quartz
+        // style); cronk has no seconds resolution, so it is discarded.
+        let (minute, hour, dom, month, dow) = match fields.len() {
+            5 => (fields[0], fields[1], fields[2], fields[3], fields[4]),
+            6 => (fields[1], fields[2], fields[3], fields[4], fields[5]),
+            n => return Err(ParseError::FieldCount(n)),
+        };
+
+        Ok(Expression {
+            minute: parse_field(minute, (0, 59), parse_number)?,
+            hour: parse_field(hour, (0, 23), parse_number)?,
+            dom: parse_field(dom, (1, 31), parse_number)?,
+            month: parse_field(month, (1, 12), parse_number)?,
+            dow: parse_weekday(dow)?,
+        })
+    }
+}
+
+/// Parses a single cron position into a `Field`, treating `*` as "any". The
+/// `bounds` give the position's natural domain, used when a step field (`*/5`)
+/// leaves its range implicit.
+fn parse_field<F>(
+    src: &str,
+    bounds: (u8, u8),
+    elem: F,
+) -> Result<Option<Field<u8>>, ParseError>
+where
+    F: Fn(&str) -> Result<u8, ParseError> + Copy,
+{
+    if src == "*" {
+        return Ok(None);
+    }
+
+    Ok(Some(parse_set(src, bounds, elem)?))
+}
+
+/// Parses the non-wildcard body of a field: a step, a comma list, a range, or a
+/// single element, where each element is decoded by `elem`.
+fn parse_set<F>(src: &str, bounds: (u8, u8), elem: F) -> Result<Field<u8>, ParseError>
+where
+    F: Fn(&str) -> Result<u8, ParseError> + Copy,
+{
+    // Every element is range-checked against the position's domain so that
+    // out-of-range input is rejected here rather than panicking later in
+    // `Schedule::advance`.
+    let decode = |s: &str| elem(s).and_then(|v| check(v, bounds));
+
+    if let Some(idx) = src.find('/') {
+        let (range, step) = src.split_at(idx);
+        let step = parse_number(&step[1..])?;
+        if step == 0 {
+            return Err(ParseError::ZeroStep);
+        }
+        let (min, max) = if range == "*" {
+            bounds
+        } else if let Some(i) = range.find('-') {
+            (decode(&range[..i])?, decode(&range[i + 1..])?)
+        } else {
+            (decode(range)?, bounds.1)
+        };
+        return Ok(Field::Step(min, max, step));
+    }
+
+    if src.contains(',') {
+        let set = src
+            .split(',')
+            .map(decode)
+            .collect::<Result<Vec<_>, _>>()?;
+        return Ok(Field::Multiple(set));
+    }
+
+    if let Some(idx) = src.find('-') {
+        let (min, max) = src.split_at(idx);
+        return Ok(Field::Range(decode(min)?, decode(&max[1..])?));
+    }
+
+    Ok(Field::Single(decode(src)?))
+}
+
+/// Ensures a decoded element falls within the position's `(min, max)` domain.
+fn check(value: u8, bounds: (u8, u8)) -> Result<u8, ParseError> {
+    if value < bounds.0 || value > bounds.1 {
+        Err(ParseError::OutOfRange(value, bounds.0, bounds.1))
+    } else {
+        Ok(value)
+    }
+}
+
+/// Parses a day-of-week field, honoring the `day#n` nth-weekday suffix.
+fn parse_weekday(src: &str) -> Result<Option<Weekday>, ParseError> {
+    if src == "*" {
+        return Ok(None);
+    }
+
+    let (field, nth) = match src.find('#') {
+        Some(idx) => {
+            let (field, rest) = src.split_at(idx);
+            let n = rest[1..]
+                .parse()
+                .map_err(|_| ParseError::Number(rest[1..].to_owned()))?;
+            (field, Some(Nth(n)))
+        }
+        None => (src, None),
+    };
+
+    Ok(Some(Weekday {
+        field: parse_set(field, (0, 6), parse_weekday_num)?,
+        nth,
+    }))
+}
+
+fn parse_number(src: &str) -> Result<u8, ParseError> {
+    src.parse().map_err(|_| ParseError::Number(src.to_owned()))
+}
+
+/// Decodes a single weekday element, accepting either a number or a three-letter
+/// English name (`Sun`..`Sat`, case-insensitive).
+fn parse_weekday_num(src: &str) -> Result<u8, ParseError> {
+    if let Ok(n) = src.parse() {
+        return Ok(n);
+    }
+
+    match src.to_ascii_lowercase().as_str() {
+        "sun" => Ok(0),
+        "mon" => Ok(1),
+        "tue" => Ok(2),
+        "wed" => Ok(3),
+        "thu" => Ok(4),
+        "fri" => Ok(5),
+        "sat" => Ok(6),
+        _ => Err(ParseError::Weekday(src.to_owned())),
+    }
+}